@@ -0,0 +1,44 @@
+// Copyright 2018-2021 the Deno authors. All rights reserved. MIT license.
+
+//! This module provides the shape of `deno.json`/`deno.jsonc` configuration
+//! files, in particular the `"lint"` section consumed by
+//! `crate::tools::lint`.
+
+use serde::Deserialize;
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LintConfig {
+  #[serde(default)]
+  pub files: LintFilesConfig,
+  #[serde(default)]
+  pub rules: LintRulesConfig,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LintFilesConfig {
+  #[serde(default)]
+  pub include: Vec<String>,
+  #[serde(default)]
+  pub exclude: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LintRulesConfig {
+  #[serde(default)]
+  pub tags: Option<Vec<String>>,
+  #[serde(default)]
+  pub include: Option<Vec<String>>,
+  #[serde(default)]
+  pub exclude: Option<Vec<String>>,
+  /// Rule codes that should only be reported as warnings, ie. not cause
+  /// `deno lint` to exit non-zero. See `--rules-warn` / `--rules-error`.
+  #[serde(default)]
+  pub warn: Option<Vec<String>>,
+  /// Rule codes that should be reported as errors. Takes precedence over
+  /// `warn` when a code appears in both lists.
+  #[serde(default)]
+  pub error: Option<Vec<String>>,
+}
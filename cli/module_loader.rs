@@ -57,8 +57,10 @@ impl CliModuleLoader {
       TypeLib::DenoWorker
     };
 
+    let import_map = program_state.maybe_import_map.clone();
+
     Rc::new(CliModuleLoader {
-      import_map: None,
+      import_map,
       lib,
       root_permissions: permissions,
       program_state,
@@ -72,7 +74,7 @@ impl ModuleLoader for CliModuleLoader {
     _op_state: Rc<RefCell<OpState>>,
     specifier: &str,
     referrer: &str,
-    is_main: bool,
+    _is_main: bool,
   ) -> Result<ModuleSpecifier, AnyError> {
     // FIXME(bartlomieju): hacky way to provide compatibility with repl
     let referrer = if referrer.is_empty() && self.program_state.flags.repl {
@@ -81,11 +83,15 @@ impl ModuleLoader for CliModuleLoader {
       referrer
     };
 
-    if !is_main {
-      if let Some(import_map) = &self.import_map {
-        return import_map
-          .resolve(specifier, referrer)
-          .map_err(AnyError::from);
+    // Consult the import map for both the main module and its dependencies.
+    // Only fall back to default resolution when the specifier isn't covered
+    // by any `imports`/`scopes` entry at all; any other import map error
+    // (e.g. for a specifier the map blocks) is returned as a hard error.
+    if let Some(import_map) = &self.import_map {
+      match import_map.resolve(specifier, referrer) {
+        Ok(resolved) => return Ok(resolved),
+        Err(import_map::ImportMapError::UnmappedBareSpecifier(..)) => {}
+        Err(err) => return Err(AnyError::from(err)),
       }
     }
 
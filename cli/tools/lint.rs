@@ -6,8 +6,10 @@
 //! At the moment it is only consumed using CLI but in
 //! the future it can be easily extended to provide
 //! the same functions as ops available in JS runtime.
+use crate::checksum;
 use crate::colors;
 use crate::config_file::LintConfig;
+use crate::deno_dir::DenoDir;
 use crate::fmt_errors;
 use crate::fs_util::{collect_files, is_supported_ext};
 use crate::tools::fmt::run_parallelized;
@@ -16,30 +18,36 @@ use deno_ast::MediaType;
 use deno_core::error::{anyhow, generic_error, AnyError, JsStackFrame};
 use deno_core::serde_json;
 use deno_lint::diagnostic::LintDiagnostic;
+use deno_lint::diagnostic::LintFix;
 use deno_lint::linter::Linter;
 use deno_lint::linter::LinterBuilder;
 use deno_lint::rules;
 use deno_lint::rules::LintRule;
 use log::debug;
 use log::info;
+use serde::Deserialize;
 use serde::Serialize;
+use std::collections::HashMap;
 use std::fs;
 use std::io::{stdin, Read};
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
 
 static STDIN_FILE_NAME: &str = "_stdin.ts";
 
 pub enum LintReporterKind {
   Pretty,
   Json,
+  Sarif,
 }
 
 fn create_reporter(kind: LintReporterKind) -> Box<dyn LintReporter + Send> {
   match kind {
     LintReporterKind::Pretty => Box::new(PrettyLintReporter::new()),
     LintReporterKind::Json => Box::new(JsonLintReporter::new()),
+    LintReporterKind::Sarif => Box::new(SarifLintReporter::new()),
   }
 }
 
@@ -48,9 +56,13 @@ pub async fn lint_files(
   rules_tags: Vec<String>,
   rules_include: Vec<String>,
   rules_exclude: Vec<String>,
+  rules_warn: Vec<String>,
+  rules_error: Vec<String>,
   args: Vec<PathBuf>,
   ignore: Vec<PathBuf>,
   json: bool,
+  sarif: bool,
+  fix: bool,
 ) -> Result<(), AnyError> {
   // First, prepare final configuration.
   // Collect included and ignored files. CLI flags take precendence
@@ -89,24 +101,44 @@ pub async fn lint_files(
     rules_exclude,
   )?;
 
+  // CLI flags take precendence over config file, same as the include/exclude
+  // rule sets above.
+  let rule_severities = Arc::new(get_rule_severities(
+    maybe_lint_config.as_ref(),
+    rules_warn,
+    rules_error,
+  ));
+
   let has_error = Arc::new(AtomicBool::new(false));
 
-  let reporter_kind = if json {
+  let reporter_kind = if sarif {
+    LintReporterKind::Sarif
+  } else if json {
     LintReporterKind::Json
   } else {
     LintReporterKind::Pretty
   };
   let reporter_lock = Arc::new(Mutex::new(create_reporter(reporter_kind)));
 
+  // Only bother with the on-disk cache for the common, non-mutating lint
+  // path; `--fix` always needs to see (and re-verify) a freshly linted file.
+  let lint_cache = if fix {
+    None
+  } else {
+    LintCache::new().map(Arc::new).ok()
+  };
+  let rules_key = rules_cache_key(&lint_rules);
+
   let no_of_files_linted =
     if args.len() == 1 && args[0].to_string_lossy() == "-" {
-      let r = lint_stdin(lint_rules);
+      let r = lint_stdin(lint_rules, fix, json || sarif);
 
       handle_lint_result(
         STDIN_FILE_NAME,
         r,
         reporter_lock.clone(),
         has_error.clone(),
+        &rule_severities,
       );
 
       1
@@ -126,13 +158,33 @@ pub async fn lint_files(
       run_parallelized(target_files, {
         let reporter_lock = reporter_lock.clone();
         let has_error = has_error.clone();
+        let lint_cache = lint_cache.clone();
+        let rules_key = rules_key.clone();
+        let rule_severities = rule_severities.clone();
         move |file_path| {
-          let r = lint_file(file_path.clone(), lint_rules.clone());
+          let r = (|| -> Result<(Vec<CachedLintDiagnostic>, String, usize), AnyError> {
+            let source_code = fs::read_to_string(&file_path)?;
+
+            if let Some(cache) = &lint_cache {
+              let cache_key = cache.key(&source_code, &rules_key);
+              if let Some(diagnostics) = cache.get(&cache_key) {
+                return Ok((diagnostics, source_code, 0));
+              }
+              let result =
+                lint_file(file_path.clone(), source_code, lint_rules.clone(), fix)?;
+              cache.set(&cache_key, &result.0);
+              return Ok(result);
+            }
+
+            lint_file(file_path.clone(), source_code, lint_rules.clone(), fix)
+          })();
+
           handle_lint_result(
             &file_path.to_string_lossy(),
             r,
             reporter_lock,
             has_error,
+            &rule_severities,
           );
           Ok(())
         }
@@ -194,54 +246,154 @@ pub fn create_linter(
 
 fn lint_file(
   file_path: PathBuf,
+  source_code: String,
   lint_rules: Arc<Vec<Box<dyn LintRule>>>,
-) -> Result<(Vec<LintDiagnostic>, String), AnyError> {
+  fix: bool,
+) -> Result<(Vec<CachedLintDiagnostic>, String, usize), AnyError> {
   let file_name = file_path.to_string_lossy().to_string();
-  let source_code = fs::read_to_string(&file_path)?;
   let media_type = MediaType::from(&file_path);
   let syntax = deno_ast::get_syntax(media_type);
 
+  let linter = create_linter(syntax, lint_rules.clone());
+
+  let (_, file_diagnostics) =
+    linter.lint(file_name.clone(), source_code.clone())?;
+
+  if !fix {
+    return Ok((to_cached_diagnostics(&file_diagnostics), source_code, 0));
+  }
+
+  let (fixed_source, fixed_count) = apply_fixes(&source_code, &file_diagnostics);
+  if fixed_count == 0 {
+    return Ok((to_cached_diagnostics(&file_diagnostics), source_code, 0));
+  }
+
+  // Re-parse and re-lint the patched source once to make sure applying the
+  // fixes didn't introduce any new problems before writing it back out.
+  // If any rule fires more often on the patched source than on the original,
+  // the fixes are discarded and the file is left untouched.
+  let syntax = deno_ast::get_syntax(media_type);
   let linter = create_linter(syntax, lint_rules);
+  let (_, fixed_diagnostics) = linter.lint(file_name, fixed_source.clone())?;
+
+  let count_code = |diagnostics: &[LintDiagnostic], code: &str| {
+    diagnostics.iter().filter(|d| d.code == code).count()
+  };
+  let introduced_new_problem = fixed_diagnostics.iter().any(|d| {
+    count_code(&fixed_diagnostics, &d.code)
+      > count_code(&file_diagnostics, &d.code)
+  });
+  if introduced_new_problem {
+    return Ok((to_cached_diagnostics(&file_diagnostics), source_code, 0));
+  }
 
-  let (_, file_diagnostics) = linter.lint(file_name, source_code.clone())?;
+  fs::write(&file_path, &fixed_source)?;
 
-  Ok((file_diagnostics, source_code))
+  Ok((
+    to_cached_diagnostics(&fixed_diagnostics),
+    fixed_source,
+    fixed_count,
+  ))
 }
 
 /// Lint stdin and write result to stdout.
 /// Treats input as TypeScript.
 /// Compatible with `--json` flag.
+///
+/// `structured_reporter_output` must be set when the `--json`/`--sarif`
+/// reporter is in play: that reporter also writes its payload to stdout on
+/// `close`, so the `--fix`ed source is written to stderr instead to avoid
+/// interleaving two payloads on the same stream.
 fn lint_stdin(
   lint_rules: Arc<Vec<Box<dyn LintRule>>>,
-) -> Result<(Vec<LintDiagnostic>, String), AnyError> {
+  fix: bool,
+  structured_reporter_output: bool,
+) -> Result<(Vec<CachedLintDiagnostic>, String, usize), AnyError> {
   let mut source_code = String::new();
   if stdin().read_to_string(&mut source_code).is_err() {
     return Err(generic_error("Failed to read from stdin"));
   }
 
   let syntax = deno_ast::get_syntax(MediaType::TypeScript);
-  let linter = create_linter(syntax, lint_rules);
+  let linter = create_linter(syntax, lint_rules.clone());
 
   let (_, file_diagnostics) =
     linter.lint(STDIN_FILE_NAME.to_string(), source_code.clone())?;
 
-  Ok((file_diagnostics, source_code))
+  if !fix {
+    return Ok((to_cached_diagnostics(&file_diagnostics), source_code, 0));
+  }
+
+  let (fixed_source, fixed_count) = apply_fixes(&source_code, &file_diagnostics);
+  if fixed_count == 0 {
+    return Ok((to_cached_diagnostics(&file_diagnostics), source_code, 0));
+  }
+
+  let syntax = deno_ast::get_syntax(MediaType::TypeScript);
+  let linter = create_linter(syntax, lint_rules);
+  let (_, file_diagnostics) =
+    linter.lint(STDIN_FILE_NAME.to_string(), fixed_source.clone())?;
+
+  if structured_reporter_output {
+    eprint!("{}", fixed_source);
+  } else {
+    print!("{}", fixed_source);
+  }
+
+  Ok((
+    to_cached_diagnostics(&file_diagnostics),
+    fixed_source,
+    fixed_count,
+  ))
+}
+
+/// Applies every fix suggested by `diagnostics` to `source`, working from
+/// the end of the file backwards so earlier byte offsets stay valid as
+/// later edits are spliced in. A fix whose range overlaps one already
+/// applied is skipped rather than risk corrupting the buffer. Returns the
+/// patched source and the number of fixes that were actually applied.
+fn apply_fixes(source: &str, diagnostics: &[LintDiagnostic]) -> (String, usize) {
+  let mut fixes: Vec<&LintFix> =
+    diagnostics.iter().flat_map(|d| d.fixes.iter()).collect();
+  fixes.sort_by(|a, b| b.range.start.cmp(&a.range.start));
+
+  let mut patched = source.to_string();
+  let mut applied = 0;
+  let mut last_applied_start = patched.len();
+
+  for fix in fixes {
+    if fix.range.end > last_applied_start {
+      continue;
+    }
+    patched.replace_range(fix.range.clone(), &fix.text);
+    last_applied_start = fix.range.start;
+    applied += 1;
+  }
+
+  (patched, applied)
 }
 
 fn handle_lint_result(
   file_path: &str,
-  result: Result<(Vec<LintDiagnostic>, String), AnyError>,
+  result: Result<(Vec<CachedLintDiagnostic>, String, usize), AnyError>,
   reporter_lock: Arc<Mutex<Box<dyn LintReporter + Send>>>,
   has_error: Arc<AtomicBool>,
+  rule_severities: &HashMap<String, LintSeverity>,
 ) {
   let mut reporter = reporter_lock.lock().unwrap();
 
   match result {
-    Ok((mut file_diagnostics, source)) => {
+    Ok((mut file_diagnostics, source, fixed_count)) => {
+      if fixed_count > 0 {
+        reporter.visit_fixed(file_path, fixed_count);
+      }
       sort_diagnostics(&mut file_diagnostics);
       for d in file_diagnostics.iter() {
-        has_error.store(true, Ordering::Relaxed);
-        reporter.visit_diagnostic(d, source.split('\n').collect());
+        let severity = severity_for(rule_severities, &d.code);
+        if severity == LintSeverity::Error {
+          has_error.store(true, Ordering::Relaxed);
+        }
+        reporter.visit_diagnostic(d, severity, source.split('\n').collect());
       }
     }
     Err(err) => {
@@ -251,8 +403,132 @@ fn handle_lint_result(
   }
 }
 
+/// The severity a rule's diagnostics are reported at. Defaults to `Error` so
+/// that unconfigured rules keep today's "any finding fails CI" behavior.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum LintSeverity {
+  Error,
+  Warning,
+}
+
+impl Default for LintSeverity {
+  fn default() -> Self {
+    LintSeverity::Error
+  }
+}
+
+fn severity_for(
+  rule_severities: &HashMap<String, LintSeverity>,
+  code: &str,
+) -> LintSeverity {
+  rule_severities.get(code).copied().unwrap_or_default()
+}
+
+/// Builds the per-rule severity map from `rules.warn`/`rules.error` in the
+/// lint config and/or the `--rules-warn`/`--rules-error` CLI flags. CLI
+/// flags take precendence over config file, same as `get_configured_rules`.
+/// Rules that appear in neither list keep the default `Error` severity.
+fn get_rule_severities(
+  maybe_lint_config: Option<&LintConfig>,
+  rules_warn: Vec<String>,
+  rules_error: Vec<String>,
+) -> HashMap<String, LintSeverity> {
+  let (config_warn, config_error) =
+    if let Some(lint_config) = maybe_lint_config {
+      (lint_config.rules.warn.clone(), lint_config.rules.error.clone())
+    } else {
+      (None, None)
+    };
+
+  let warn_codes = if !rules_warn.is_empty() {
+    rules_warn
+  } else {
+    config_warn.unwrap_or_else(Vec::new)
+  };
+
+  let error_codes = if !rules_error.is_empty() {
+    rules_error
+  } else {
+    config_error.unwrap_or_else(Vec::new)
+  };
+
+  let mut severities = HashMap::new();
+  for code in warn_codes {
+    severities.insert(code, LintSeverity::Warning);
+  }
+  // Explicit `error` entries win over `warn` if a code is listed in both.
+  for code in error_codes {
+    severities.insert(code, LintSeverity::Error);
+  }
+  severities
+}
+
+/// A line/column position within a source file, mirroring
+/// `deno_lint::diagnostic::Position`.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct CachedPosition {
+  pub line_index: usize,
+  pub column_index: usize,
+}
+
+/// A start/end span within a source file, mirroring
+/// `deno_lint::diagnostic::Range`.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct CachedRange {
+  pub start: CachedPosition,
+  pub end: CachedPosition,
+}
+
+/// The subset of `deno_lint::diagnostic::LintDiagnostic` that reporters and
+/// the on-disk lint cache need. `LintDiagnostic` itself only implements
+/// `Serialize` upstream, so it can't be round-tripped through the cache;
+/// this DTO is what flows through `handle_lint_result` and the reporters
+/// instead, for both freshly linted files and cache hits alike.
+#[derive(Clone, Serialize, Deserialize)]
+struct CachedLintDiagnostic {
+  code: String,
+  message: String,
+  filename: String,
+  range: CachedRange,
+  hint: Option<String>,
+}
+
+impl From<&LintDiagnostic> for CachedLintDiagnostic {
+  fn from(d: &LintDiagnostic) -> Self {
+    CachedLintDiagnostic {
+      code: d.code.clone(),
+      message: d.message.clone(),
+      filename: d.filename.clone(),
+      range: CachedRange {
+        start: CachedPosition {
+          line_index: d.range.start.line_index,
+          column_index: d.range.start.column_index,
+        },
+        end: CachedPosition {
+          line_index: d.range.end.line_index,
+          column_index: d.range.end.column_index,
+        },
+      },
+      hint: d.hint.clone(),
+    }
+  }
+}
+
+fn to_cached_diagnostics(
+  diagnostics: &[LintDiagnostic],
+) -> Vec<CachedLintDiagnostic> {
+  diagnostics.iter().map(CachedLintDiagnostic::from).collect()
+}
+
 trait LintReporter {
-  fn visit_diagnostic(&mut self, d: &LintDiagnostic, source_lines: Vec<&str>);
+  fn visit_diagnostic(
+    &mut self,
+    d: &CachedLintDiagnostic,
+    severity: LintSeverity,
+    source_lines: Vec<&str>,
+  );
+  fn visit_fixed(&mut self, file_path: &str, count: usize);
   fn visit_error(&mut self, file_path: &str, err: &AnyError);
   fn close(&mut self, check_count: usize);
 }
@@ -264,20 +540,39 @@ struct LintError {
 }
 
 struct PrettyLintReporter {
-  lint_count: u32,
+  error_count: u32,
+  warning_count: u32,
+  fixed_count: u32,
 }
 
 impl PrettyLintReporter {
   fn new() -> PrettyLintReporter {
-    PrettyLintReporter { lint_count: 0 }
+    PrettyLintReporter {
+      error_count: 0,
+      warning_count: 0,
+      fixed_count: 0,
+    }
   }
 }
 
 impl LintReporter for PrettyLintReporter {
-  fn visit_diagnostic(&mut self, d: &LintDiagnostic, source_lines: Vec<&str>) {
-    self.lint_count += 1;
-
-    let pretty_message = format!("({}) {}", colors::red(&d.code), &d.message);
+  fn visit_diagnostic(
+    &mut self,
+    d: &CachedLintDiagnostic,
+    severity: LintSeverity,
+    source_lines: Vec<&str>,
+  ) {
+    let pretty_code = match severity {
+      LintSeverity::Error => {
+        self.error_count += 1;
+        colors::red(&d.code)
+      }
+      LintSeverity::Warning => {
+        self.warning_count += 1;
+        colors::yellow(&d.code)
+      }
+    };
+    let pretty_message = format!("({}) {}", pretty_code, &d.message);
 
     let message = format_diagnostic(
       &d.code,
@@ -291,23 +586,40 @@ impl LintReporter for PrettyLintReporter {
         // todo(#11111): make 1-indexed as well
         Some(d.range.start.column_index as i64),
       )),
+      severity,
     );
 
     eprintln!("{}\n", message);
   }
 
+  fn visit_fixed(&mut self, file_path: &str, count: usize) {
+    self.fixed_count += count as u32;
+    info!("Fixed {} problem(s) in {}", count, file_path);
+  }
+
   fn visit_error(&mut self, file_path: &str, err: &AnyError) {
     eprintln!("Error linting: {}", file_path);
     eprintln!("   {}", err);
   }
 
   fn close(&mut self, check_count: usize) {
-    match self.lint_count {
-      1 => info!("Found 1 problem"),
-      n if n > 1 => info!("Found {} problems", self.lint_count),
+    match self.fixed_count {
+      1 => info!("Fixed 1 problem"),
+      n if n > 1 => info!("Fixed {} problems", n),
       _ => (),
     }
 
+    match (self.error_count, self.warning_count) {
+      (0, 0) => (),
+      (errors, warnings) => info!(
+        "Found {} error{}, {} warning{}",
+        errors,
+        if errors == 1 { "" } else { "s" },
+        warnings,
+        if warnings == 1 { "" } else { "s" },
+      ),
+    }
+
     match check_count {
       n if n <= 1 => info!("Checked {} file", n),
       n if n > 1 => info!("Checked {} files", n),
@@ -320,11 +632,16 @@ pub fn format_diagnostic(
   diagnostic_code: &str,
   message_line: &str,
   source_lines: &[&str],
-  range: deno_lint::diagnostic::Range,
+  range: CachedRange,
   maybe_hint: Option<&String>,
   formatted_location: &str,
+  severity: LintSeverity,
 ) -> String {
   let mut lines = vec![];
+  let caret = |s: String| match severity {
+    LintSeverity::Error => colors::red(&s).to_string(),
+    LintSeverity::Warning => colors::yellow(&s).to_string(),
+  };
 
   for (i, line) in source_lines
     .iter()
@@ -337,9 +654,7 @@ pub fn format_diagnostic(
       lines.push(format!(
         "{}{}",
         " ".repeat(range.start.column_index),
-        colors::red(
-          &"^".repeat(range.end.column_index - range.start.column_index)
-        )
+        caret("^".repeat(range.end.column_index - range.start.column_index))
       ));
     } else {
       let line_len = line.len();
@@ -347,13 +662,12 @@ pub fn format_diagnostic(
         lines.push(format!(
           "{}{}",
           " ".repeat(range.start.column_index),
-          colors::red(&"^".repeat(line_len - range.start.column_index))
+          caret("^".repeat(line_len - range.start.column_index))
         ));
       } else if range.end.line_index == i {
-        lines
-          .push(colors::red(&"^".repeat(range.end.column_index)).to_string());
+        lines.push(caret("^".repeat(range.end.column_index)));
       } else if line_len != 0 {
-        lines.push(colors::red(&"^".repeat(line_len)).to_string());
+        lines.push(caret("^".repeat(line_len)));
       }
     }
   }
@@ -379,10 +693,24 @@ pub fn format_diagnostic(
   )
 }
 
+#[derive(Serialize)]
+struct LintFixedFile {
+  file_path: String,
+  count: usize,
+}
+
+#[derive(Serialize)]
+struct JsonLintDiagnostic {
+  #[serde(flatten)]
+  diagnostic: CachedLintDiagnostic,
+  severity: LintSeverity,
+}
+
 #[derive(Serialize)]
 struct JsonLintReporter {
-  diagnostics: Vec<LintDiagnostic>,
+  diagnostics: Vec<JsonLintDiagnostic>,
   errors: Vec<LintError>,
+  fixed: Vec<LintFixedFile>,
 }
 
 impl JsonLintReporter {
@@ -390,13 +718,29 @@ impl JsonLintReporter {
     JsonLintReporter {
       diagnostics: Vec::new(),
       errors: Vec::new(),
+      fixed: Vec::new(),
     }
   }
 }
 
 impl LintReporter for JsonLintReporter {
-  fn visit_diagnostic(&mut self, d: &LintDiagnostic, _source_lines: Vec<&str>) {
-    self.diagnostics.push(d.clone());
+  fn visit_diagnostic(
+    &mut self,
+    d: &CachedLintDiagnostic,
+    severity: LintSeverity,
+    _source_lines: Vec<&str>,
+  ) {
+    self.diagnostics.push(JsonLintDiagnostic {
+      diagnostic: d.clone(),
+      severity,
+    });
+  }
+
+  fn visit_fixed(&mut self, file_path: &str, count: usize) {
+    self.fixed.push(LintFixedFile {
+      file_path: file_path.to_string(),
+      count,
+    });
   }
 
   fn visit_error(&mut self, file_path: &str, err: &AnyError) {
@@ -407,31 +751,154 @@ impl LintReporter for JsonLintReporter {
   }
 
   fn close(&mut self, _check_count: usize) {
-    sort_diagnostics(&mut self.diagnostics);
+    self
+      .diagnostics
+      .sort_by(|a, b| diagnostic_order(&a.diagnostic, &b.diagnostic));
     let json = serde_json::to_string_pretty(&self);
     println!("{}", json.unwrap());
   }
 }
 
-fn sort_diagnostics(diagnostics: &mut Vec<LintDiagnostic>) {
-  // Sort so that we guarantee a deterministic output which is useful for tests
-  diagnostics.sort_by(|a, b| {
-    use std::cmp::Ordering;
-    let file_order = a.filename.cmp(&b.filename);
-    match file_order {
-      Ordering::Equal => {
-        let line_order =
-          a.range.start.line_index.cmp(&b.range.start.line_index);
-        match line_order {
-          Ordering::Equal => {
-            a.range.start.column_index.cmp(&b.range.start.column_index)
-          }
-          _ => line_order,
+/// Reporter that accumulates diagnostics and, on `close`, serializes them as
+/// a SARIF 2.1.0 log so `deno lint` output can be ingested by GitHub Advanced
+/// Security and other SARIF-consuming code-scanning tools.
+struct SarifLintReporter {
+  diagnostics: Vec<CachedLintDiagnostic>,
+  severities: Vec<LintSeverity>,
+}
+
+impl SarifLintReporter {
+  fn new() -> SarifLintReporter {
+    SarifLintReporter {
+      diagnostics: Vec::new(),
+      severities: Vec::new(),
+    }
+  }
+}
+
+impl LintReporter for SarifLintReporter {
+  fn visit_diagnostic(
+    &mut self,
+    d: &CachedLintDiagnostic,
+    severity: LintSeverity,
+    _source_lines: Vec<&str>,
+  ) {
+    self.diagnostics.push(d.clone());
+    self.severities.push(severity);
+  }
+
+  fn visit_fixed(&mut self, _file_path: &str, _count: usize) {}
+
+  fn visit_error(&mut self, file_path: &str, err: &AnyError) {
+    eprintln!("Error linting: {}", file_path);
+    eprintln!("   {}", err);
+  }
+
+  fn close(&mut self, _check_count: usize) {
+    let mut diagnostics = self
+      .diagnostics
+      .drain(..)
+      .zip(self.severities.drain(..))
+      .collect::<Vec<_>>();
+    diagnostics.sort_by(|(a, _), (b, _)| diagnostic_order(a, b));
+
+    let mut rule_codes = diagnostics
+      .iter()
+      .map(|(d, _)| d.code.clone())
+      .collect::<Vec<_>>();
+    rule_codes.sort();
+    rule_codes.dedup();
+
+    let rules = rule_codes
+      .iter()
+      .map(|code| {
+        serde_json::json!({
+          "id": code,
+          "helpUri": format!("https://lint.deno.land/#{}", code),
+        })
+      })
+      .collect::<Vec<_>>();
+
+    // SARIF only has error/warning/note levels; map our two severities
+    // directly so a rule demoted via the severity map still shows up as a
+    // warning instead of a blocking error in SARIF-consuming CI gates.
+    let results = diagnostics
+      .iter()
+      .map(|(d, severity)| {
+        let level = match severity {
+          LintSeverity::Error => "error",
+          LintSeverity::Warning => "warning",
+        };
+        serde_json::json!({
+          "ruleId": d.code,
+          "level": level,
+          "message": {
+            "text": d.message,
+          },
+          "locations": [{
+            "physicalLocation": {
+              "artifactLocation": {
+                // GitHub code scanning (and most SARIF consumers) expect a
+                // repo-relative `artifactLocation.uri` with no `file://`
+                // scheme; `d.filename` is whatever path the user passed to
+                // `deno lint`, so just normalize separators for Windows.
+                "uri": d.filename.replace('\\', "/"),
+              },
+              "region": {
+                "startLine": d.range.start.line_index + 1,
+                "startColumn": d.range.start.column_index + 1,
+                "endLine": d.range.end.line_index + 1,
+                "endColumn": d.range.end.column_index + 1,
+              },
+            },
+          }],
+        })
+      })
+      .collect::<Vec<_>>();
+
+    let sarif = serde_json::json!({
+      "version": "2.1.0",
+      "$schema": "https://raw.githubusercontent.com/oasis-tcs/sarif-spec/master/Schemata/sarif-schema-2.1.0.json",
+      "runs": [{
+        "tool": {
+          "driver": {
+            "name": "deno_lint",
+            "informationUri": "https://lint.deno.land/",
+            "rules": rules,
+          },
+        },
+        "results": results,
+      }],
+    });
+
+    println!("{}", serde_json::to_string_pretty(&sarif).unwrap());
+  }
+}
+
+fn diagnostic_order(
+  a: &CachedLintDiagnostic,
+  b: &CachedLintDiagnostic,
+) -> std::cmp::Ordering {
+  use std::cmp::Ordering;
+  let file_order = a.filename.cmp(&b.filename);
+  match file_order {
+    Ordering::Equal => {
+      let line_order =
+        a.range.start.line_index.cmp(&b.range.start.line_index);
+      match line_order {
+        Ordering::Equal => {
+          a.range.start.column_index.cmp(&b.range.start.column_index)
         }
+        _ => line_order,
       }
-      _ => file_order,
     }
-  });
+    _ => file_order,
+  }
+}
+
+fn sort_diagnostics(diagnostics: &mut Vec<CachedLintDiagnostic>) {
+  // Sort so that we guarantee a deterministic output which is useful for tests
+  diagnostics.sort_by(diagnostic_order);
 }
 
 fn get_configured_rules(
@@ -489,3 +956,82 @@ fn get_configured_rules(
 
   Ok(configured_rules)
 }
+
+/// A hash of the codes of the resolved rule set, used together with a file's
+/// own content hash to key the on-disk lint cache. Any change to which rules
+/// are enabled invalidates every cached entry.
+fn rules_cache_key(lint_rules: &[Box<dyn LintRule>]) -> String {
+  let codes = lint_rules
+    .iter()
+    .map(|rule| rule.code())
+    .collect::<Vec<_>>()
+    .join(",");
+  checksum::gen(&[codes.as_bytes()])
+}
+
+/// Entries untouched for longer than this are swept on cache construction.
+/// Every edit to a file orphans its previous cache entry (the key changes
+/// with the content hash), so without a sweep the cache directory would grow
+/// without bound across normal iterative editing.
+const LINT_CACHE_MAX_AGE: Duration = Duration::from_secs(14 * 24 * 60 * 60);
+
+/// On-disk cache of lint results, keyed by a hash of a file's content
+/// combined with a hash of the resolved rule set. Lets `lint_files` skip
+/// re-parsing and re-linting files that haven't changed since the last run.
+struct LintCache {
+  dir: PathBuf,
+}
+
+impl LintCache {
+  fn new() -> Result<Self, AnyError> {
+    let dir = DenoDir::new(None)?.root.join("lint_cache");
+    fs::create_dir_all(&dir)?;
+    let cache = Self { dir };
+    cache.prune_stale_entries();
+    Ok(cache)
+  }
+
+  /// Removes entries whose last write is older than `LINT_CACHE_MAX_AGE`.
+  /// Best-effort: I/O errors for individual entries are ignored, since a
+  /// failed sweep shouldn't stop linting from proceeding.
+  fn prune_stale_entries(&self) {
+    let entries = match fs::read_dir(&self.dir) {
+      Ok(entries) => entries,
+      Err(_) => return,
+    };
+
+    for entry in entries.flatten() {
+      let is_stale = entry
+        .metadata()
+        .and_then(|metadata| metadata.modified())
+        .and_then(|modified| {
+          Ok(
+            SystemTime::now()
+              .duration_since(modified)
+              .unwrap_or_default()
+              > LINT_CACHE_MAX_AGE,
+          )
+        })
+        .unwrap_or(false);
+
+      if is_stale {
+        let _ = fs::remove_file(entry.path());
+      }
+    }
+  }
+
+  fn key(&self, source_code: &str, rules_key: &str) -> String {
+    checksum::gen(&[source_code.as_bytes(), rules_key.as_bytes()])
+  }
+
+  fn get(&self, key: &str) -> Option<Vec<CachedLintDiagnostic>> {
+    let bytes = fs::read(self.dir.join(key)).ok()?;
+    serde_json::from_slice(&bytes).ok()
+  }
+
+  fn set(&self, key: &str, diagnostics: &[CachedLintDiagnostic]) {
+    if let Ok(bytes) = serde_json::to_vec(diagnostics) {
+      let _ = fs::write(self.dir.join(key), bytes);
+    }
+  }
+}